@@ -7,6 +7,8 @@ pub enum Error {
     BadIndexName,
     #[error("Malformed task.")]
     MalformedTask,
+    #[error("Unsupported dump version `{0}`. The dump was probably created by a more recent version of Meilisearch.")]
+    UnsupportedDumpVersion(String),
 
     #[error(transparent)]
     Io(#[from] std::io::Error),
@@ -29,6 +31,7 @@ impl ErrorCode for Error {
             // all these errors should never be raised when creating a dump, thus no error code should be associated.
             Error::BadIndexName => Code::Internal,
             Error::MalformedTask => Code::Internal,
+            Error::UnsupportedDumpVersion(_) => Code::Internal,
         }
     }
 }
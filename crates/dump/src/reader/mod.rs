@@ -9,7 +9,7 @@ use self::compat::v4_to_v5::CompatV4ToV5;
 use self::compat::v5_to_v6::{CompatIndexV5ToV6, CompatV5ToV6};
 use self::v5::V5Reader;
 use self::v6::{V6IndexReader, V6Reader};
-use crate::{Result, Version};
+use crate::{Error, Result, Version};
 
 mod compat;
 
@@ -39,10 +39,25 @@ impl DumpReader {
         #[derive(Deserialize)]
         #[serde(rename_all = "camelCase")]
         struct MetadataVersion {
-            pub dump_version: Version,
+            pub dump_version: serde_json::Value,
         }
         let mut meta_file = File::open(path.path().join("metadata.json"))?;
         let MetadataVersion { dump_version } = serde_json::from_reader(&mut meta_file)?;
+        let dump_version = match Version::deserialize(&dump_version) {
+            Ok(version) => version,
+            Err(e) => {
+                // A dump coming from a more recent Meilisearch has a version we don't know about,
+                // any other value means the dump is malformed.
+                let version = dump_version.as_str().unwrap_or_default();
+                let number = version.strip_prefix('V').and_then(|n| n.parse::<u32>().ok());
+                return match number {
+                    Some(number) if number > 6 => {
+                        Err(Error::UnsupportedDumpVersion(version.to_string()))
+                    }
+                    _ => Err(e.into()),
+                };
+            }
+        };
 
         match dump_version {
             Version::V1 => {
@@ -197,6 +212,38 @@ pub(crate) mod test {
     use super::*;
     use crate::reader::v6::RuntimeTogglableFeatures;
 
+    /// Builds a dump containing only the given `metadata.json`.
+    fn dump_with_metadata(metadata: &str) -> Vec<u8> {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("metadata.json"), metadata).unwrap();
+        let mut dump = Vec::new();
+        let gz = flate2::write::GzEncoder::new(&mut dump, flate2::Compression::default());
+        let mut archive = tar::Builder::new(gz);
+        archive.append_dir_all(".", dir.path()).unwrap();
+        archive.into_inner().unwrap().finish().unwrap();
+        dump
+    }
+
+    #[test]
+    fn import_dump_from_a_newer_version() {
+        let dump = dump_with_metadata(
+            r#"{ "dumpVersion": "V7", "dbVersion": "2.0.0", "dumpDate": "2026-10-15T00:00:00Z" }"#,
+        );
+        let error = DumpReader::open(dump.as_slice()).map(|_| ()).unwrap_err();
+        insta::assert_snapshot!(error, @"Unsupported dump version `V7`. The dump was probably created by a more recent version of Meilisearch.");
+    }
+
+    #[test]
+    fn import_dump_with_a_malformed_version() {
+        for dump_version in [r#""v6""#, r#""V""#, "null", "7"] {
+            let dump = dump_with_metadata(&format!(
+                r#"{{ "dumpVersion": {dump_version}, "dbVersion": "1.11.0", "dumpDate": "2026-10-15T00:00:00Z" }}"#
+            ));
+            let error = DumpReader::open(dump.as_slice()).map(|_| ()).unwrap_err();
+            assert!(matches!(error, Error::Serde(_)), "{dump_version}: {error}");
+        }
+    }
+
     #[test]
     fn import_dump_v6_with_vectors() {
         // dump containing two indexes
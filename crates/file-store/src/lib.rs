@@ -25,13 +25,20 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[derive(Clone, Debug)]
 pub struct FileStore {
     path: PathBuf,
+    fsync: bool,
 }
 
 impl FileStore {
     pub fn new(path: impl AsRef<Path>) -> Result<FileStore> {
         let path = path.as_ref().to_path_buf();
         std::fs::create_dir_all(&path)?;
-        Ok(FileStore { path })
+        Ok(FileStore { path, fsync: false })
+    }
+
+    /// Makes the update files, and the directory containing them, be synced to disk
+    /// when they are persisted. Slower, but an acknowledged update survives a power loss.
+    pub fn with_fsync(self, fsync: bool) -> FileStore {
+        FileStore { fsync, ..self }
     }
 }
 
@@ -42,7 +49,7 @@ impl FileStore {
         let file = NamedTempFile::new_in(&self.path)?;
         let uuid = Uuid::new_v4();
         let path = self.path.join(uuid.to_string());
-        let update_file = File { file: Some(file), path };
+        let update_file = File { file: Some(file), path, fsync: self.fsync };
 
         Ok((uuid, update_file))
     }
@@ -53,7 +60,7 @@ impl FileStore {
         let file = NamedTempFile::new_in(&self.path)?;
         let uuid = Uuid::from_u128(uuid);
         let path = self.path.join(uuid.to_string());
-        let update_file = File { file: Some(file), path };
+        let update_file = File { file: Some(file), path, fsync: self.fsync };
 
         Ok((uuid, update_file))
     }
@@ -87,10 +94,25 @@ impl FileStore {
     }
 
     /// Compute the size of all the updates contained in the file store.
+    ///
+    /// Entries deleted during the scan, by a batch being processed for example, are
+    /// silently not counted. Entries that can't be read for another reason, or whose name
+    /// isn't an update uuid, are logged and not counted either.
     pub fn compute_total_size(&self) -> Result<u64> {
         let mut total = 0;
         for uuid in self.all_uuids()? {
-            total += self.compute_size(uuid?).unwrap_or_default();
+            let uuid = match uuid {
+                Ok(uuid) => uuid,
+                Err(e) => {
+                    tracing::warn!("Can't read an entry of the update files directory: {e}");
+                    continue;
+                }
+            };
+            match std::fs::metadata(self.get_update_path(uuid)) {
+                Ok(metadata) => total += metadata.len(),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => (),
+                Err(e) => tracing::warn!("Can't read the size of update file {uuid}: {e}"),
+            }
         }
         Ok(total)
     }
@@ -133,21 +155,44 @@ impl FileStore {
 pub struct File {
     path: PathBuf,
     file: Option<NamedTempFile>,
+    fsync: bool,
 }
 
 impl File {
     pub fn dry_file() -> Result<Self> {
-        Ok(Self { path: PathBuf::new(), file: None })
+        Ok(Self { path: PathBuf::new(), file: None, fsync: false })
     }
 
     pub fn persist(self) -> Result<()> {
         if let Some(file) = self.file {
+            if self.fsync {
+                sync_all(file.as_file())?;
+            }
             file.persist(&self.path)?;
+            // the rename must also reach the disk for the file to be found after a crash
+            #[cfg(unix)]
+            if self.fsync {
+                if let Some(dir) = self.path.parent() {
+                    // The file is already at its final path, but the caller only sees an error
+                    // and can't know it, so we remove it to not leave an unreferenced update file.
+                    if let Err(e) = StdFile::open(dir).and_then(|dir| sync_all(&dir)) {
+                        let _ = std::fs::remove_file(&self.path);
+                        return Err(e.into());
+                    }
+                }
+            }
         }
         Ok(())
     }
 }
 
+/// Syncs the file to disk. Test builds count the calls in `test::SYNCS`.
+fn sync_all(file: &StdFile) -> std::io::Result<()> {
+    #[cfg(test)]
+    test::SYNCS.with(|syncs| syncs.set(syncs.get() + 1));
+    file.sync_all()
+}
+
 impl Write for File {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
         if let Some(file) = self.file.as_mut() {
@@ -168,12 +213,18 @@ impl Write for File {
 
 #[cfg(test)]
 mod test {
+    use std::cell::Cell;
     use std::io::Write;
 
     use tempfile::TempDir;
 
     use super::*;
 
+    thread_local! {
+        /// The number of files synced to disk by the current thread.
+        pub(super) static SYNCS: Cell<usize> = const { Cell::new(0) };
+    }
+
     #[test]
     fn all_uuids() {
         let dir = TempDir::new().unwrap();
@@ -195,4 +246,47 @@ mod test {
         expected.sort();
         assert_eq!(all_uuids, expected);
     }
+
+    #[test]
+    fn compute_total_size() {
+        let dir = TempDir::new().unwrap();
+        let fs = FileStore::new(dir.path()).unwrap();
+        let (_, mut file) = fs.new_update().unwrap();
+        file.write_all(b"Hello world").unwrap();
+        file.persist().unwrap();
+        let (_, mut file) = fs.new_update().unwrap();
+        file.write_all(b"Bye").unwrap();
+        file.persist().unwrap();
+
+        // a file that is not an update must not prevent the others from being counted
+        std::fs::write(dir.path().join("not-an-update"), b"Hello").unwrap();
+
+        assert_eq!(fs.compute_total_size().unwrap(), 14);
+    }
+
+    #[test]
+    fn persist_with_fsync() {
+        let dir = TempDir::new().unwrap();
+        let fs = FileStore::new(dir.path()).unwrap().with_fsync(true);
+        let (uuid, mut file) = fs.new_update().unwrap();
+        file.write_all(b"Hello world").unwrap();
+        file.persist().unwrap();
+
+        let content = std::fs::read(fs.get_update_path(uuid)).unwrap();
+        assert_eq!(content, b"Hello world");
+        // the file, and on unix the directory containing it, are synced
+        let expected = if cfg!(unix) { 2 } else { 1 };
+        assert_eq!(SYNCS.get(), expected);
+    }
+
+    #[test]
+    fn persist_without_fsync() {
+        let dir = TempDir::new().unwrap();
+        let fs = FileStore::new(dir.path()).unwrap();
+        let (_, mut file) = fs.new_update().unwrap();
+        file.write_all(b"Hello world").unwrap();
+        file.persist().unwrap();
+
+        assert_eq!(SYNCS.get(), 0);
+    }
 }
@@ -22,6 +22,8 @@ use std::ffi::OsStr;
 use std::fmt;
 use std::fs::{self, File};
 use std::io::BufWriter;
+use std::path::Path;
+use std::time::Instant;
 
 use dump::IndexMetadata;
 use meilisearch_types::error::Code;
@@ -47,6 +49,17 @@ use crate::autobatcher::{self, BatchKind};
 use crate::utils::{self, swap_index_uid_in_task};
 use crate::{Error, IndexScheduler, MustStopProcessing, ProcessingTasks, Result, TaskId};
 
+/// The name of the database whose tasks are stored in `tasks_path`.
+pub(crate) fn db_name(tasks_path: &Path) -> &str {
+    tasks_path.parent().and_then(Path::file_name).and_then(OsStr::to_str).unwrap_or("data.ms")
+}
+
+/// The prefix of the temporary file a snapshot of the `db_name` database is written to
+/// before being moved to its final path.
+pub(crate) fn temp_snapshot_prefix(db_name: &str) -> String {
+    format!(".{db_name}.snapshot")
+}
+
 /// Represents a combination of tasks that can all be processed at the same time.
 ///
 /// A batch contains the set of tasks that it represents (accessible through
@@ -718,6 +731,7 @@ impl IndexScheduler {
                 Ok(tasks)
             }
             Batch::SnapshotCreation(mut tasks) => {
+                let started_at = Instant::now();
                 fs::create_dir_all(&self.snapshots_path)?;
                 let temp_snapshot_dir = tempfile::tempdir()?;
 
@@ -742,6 +756,10 @@ impl IndexScheduler {
                 fs::create_dir_all(&dst)?;
                 self.env.copy_to_file(dst.join("data.mdb"), CompactionOption::Enabled)?;
 
+                if self.must_stop_processing.get() {
+                    return Err(Error::AbortedTask);
+                }
+
                 // 2.2 Create a read transaction on the index-scheduler
                 let rtxn = self.env.read_txn()?;
 
@@ -751,6 +769,10 @@ impl IndexScheduler {
 
                 // 2.4 Only copy the update files of the enqueued tasks
                 for task_id in self.get_status(&rtxn, Status::Enqueued)? {
+                    if self.must_stop_processing.get() {
+                        return Err(Error::AbortedTask);
+                    }
+
                     let task = self.get_task(&rtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
                     if let Some(content_uuid) = task.content_uuid() {
                         let src = self.file_store.get_update_path(content_uuid);
@@ -761,6 +783,10 @@ impl IndexScheduler {
 
                 // 3. Snapshot every indexes
                 for result in self.index_mapper.index_mapping.iter(&rtxn)? {
+                    if self.must_stop_processing.get() {
+                        return Err(Error::AbortedTask);
+                    }
+
                     let (name, uuid) = result?;
                     let index = self.index_mapper.index(&rtxn, name)?;
                     let dst = temp_snapshot_dir.path().join("indexes").join(uuid.to_string());
@@ -782,21 +808,31 @@ impl IndexScheduler {
                 }?;
                 auth.copy_to_file(dst.join("data.mdb"), CompactionOption::Enabled)?;
 
+                if self.must_stop_processing.get() {
+                    return Err(Error::AbortedTask);
+                }
+
                 // 5. Copy and tarball the flat snapshot
                 // 5.1 Find the original name of the database
                 // TODO find a better way to get this path
-                let mut base_path = self.env.path().to_owned();
-                base_path.pop();
-                let db_name = base_path.file_name().and_then(OsStr::to_str).unwrap_or("data.ms");
+                let db_name = db_name(self.env.path());
 
                 // 5.2 Tarball the content of the snapshot in a tempfile with a .snapshot extension
+                //
+                // The tempfile is named after the database so that a tempfile left by a crash
+                // can be recognized and removed, at startup or by the next snapshot.
                 let snapshot_path = self.snapshots_path.join(format!("{}.snapshot", db_name));
-                let temp_snapshot_file = tempfile::NamedTempFile::new_in(&self.snapshots_path)?;
+                let temp_prefix = temp_snapshot_prefix(db_name);
+                utils::remove_leftover_temp_files(&self.snapshots_path, &temp_prefix);
+                let temp_snapshot_file = tempfile::Builder::new()
+                    .prefix(&temp_prefix)
+                    .tempfile_in(&self.snapshots_path)?;
                 compression::to_tar_gz(temp_snapshot_dir.path(), temp_snapshot_file.path())?;
-                let file = temp_snapshot_file.persist(snapshot_path)?;
+                let file = temp_snapshot_file.persist(&snapshot_path)?;
 
                 // 5.3 Change the permission to make the snapshot readonly
-                let mut permissions = file.metadata()?.permissions();
+                let metadata = file.metadata()?;
+                let mut permissions = metadata.permissions();
                 permissions.set_readonly(true);
                 #[cfg(unix)]
                 {
@@ -808,6 +844,13 @@ impl IndexScheduler {
 
                 file.set_permissions(permissions)?;
 
+                tracing::info!(
+                    path = %snapshot_path.display(),
+                    size = metadata.len(),
+                    duration = ?started_at.elapsed(),
+                    "Snapshot created"
+                );
+
                 for task in &mut tasks {
                     task.status = Status::Succeeded;
                 }
@@ -32,7 +32,7 @@ pub mod uuid_codec;
 pub type Result<T> = std::result::Result<T, Error>;
 pub type TaskId = u32;
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::{self, BufReader, Read};
 use std::ops::{Bound, RangeBounds};
 use std::panic::{catch_unwind, AssertUnwindSafe};
@@ -241,6 +241,8 @@ pub struct IndexSchedulerOptions {
     pub tasks_path: PathBuf,
     /// The path to the file store containing the files associated to the tasks.
     pub update_file_path: PathBuf,
+    /// Whether the files associated to the tasks are synced to disk before their task is registered.
+    pub fsync_update_files: bool,
     /// The path to the folder containing meilisearch's indexes.
     pub indexes_path: PathBuf,
     /// The path to the folder containing the snapshots.
@@ -427,6 +429,10 @@ impl IndexScheduler {
         std::fs::create_dir_all(&options.update_file_path)?;
         std::fs::create_dir_all(&options.indexes_path)?;
         std::fs::create_dir_all(&options.dumps_path)?;
+        utils::remove_leftover_temp_files(
+            &options.snapshots_path,
+            &batch::temp_snapshot_prefix(batch::db_name(&options.tasks_path)),
+        );
 
         if cfg!(windows) && options.enable_mdb_writemap {
             // programmer error if this happens: in normal use passing the option on Windows is an error in main
@@ -458,7 +464,8 @@ impl IndexScheduler {
 
         let features = features::FeatureData::new(&env, options.instance_features)?;
 
-        let file_store = FileStore::new(&options.update_file_path)?;
+        let file_store =
+            FileStore::new(&options.update_file_path)?.with_fsync(options.fsync_update_files);
 
         let mut wtxn = env.write_txn()?;
         let all_tasks = env.create_database(&mut wtxn, Some(db_name::ALL_TASKS))?;
@@ -517,6 +524,11 @@ impl IndexScheduler {
             features,
         };
 
+        // Nothing can be enqueued yet, thus we can safely get rid of the files no task will ever use.
+        if let Err(e) = this.delete_orphaned_update_files() {
+            tracing::error!("Failed to delete the orphaned update files: {e}");
+        }
+
         this.run();
         Ok(this)
     }
@@ -873,6 +885,20 @@ impl IndexScheduler {
         Ok(res)
     }
 
+    /// Return the enqueue date of the oldest task that is not finished yet, if any.
+    ///
+    /// Processing tasks are still stored as enqueued, thus they are taken into account.
+    pub fn oldest_enqueued_at(&self) -> Result<Option<OffsetDateTime>> {
+        let rtxn = self.read_txn()?;
+        match self.get_status(&rtxn, Status::Enqueued)?.min() {
+            Some(task_id) => {
+                let task = self.get_task(&rtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
+                Ok(Some(task.enqueued_at))
+            }
+            None => Ok(None),
+        }
+    }
+
     // Return true if there is at least one task that is processing.
     pub fn is_task_processing(&self) -> Result<bool> {
         Ok(!self.processing_tasks.read().unwrap().processing.is_empty())
@@ -1115,6 +1141,51 @@ impl IndexScheduler {
         Ok(self.file_store.delete(uuid)?)
     }
 
+    /// Delete the update files that are not referenced by any enqueued task.
+    ///
+    /// Such files are left behind when Meilisearch stops between the persistence of an update
+    /// file and the registration of its task, or between the end of a batch and the deletion of
+    /// its update files. It must not be called while tasks can be registered.
+    pub(crate) fn delete_orphaned_update_files(&self) -> Result<()> {
+        let rtxn = self.env.read_txn()?;
+        let mut referenced = HashSet::new();
+        for task_id in self.get_status(&rtxn, Status::Enqueued)? {
+            let task = self.get_task(&rtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
+            if let Some(content_uuid) = task.content_uuid() {
+                referenced.insert(content_uuid);
+            }
+        }
+        drop(rtxn);
+
+        let mut deleted_files = 0;
+        let mut reclaimed_bytes = 0;
+        for uuid in self.file_store.all_uuids()? {
+            let uuid = match uuid {
+                Ok(uuid) => uuid,
+                Err(e) => {
+                    tracing::warn!("Skipping an unexpected entry of the update files: {e}");
+                    continue;
+                }
+            };
+            if referenced.contains(&uuid) {
+                continue;
+            }
+            let size = self.file_store.compute_size(uuid).unwrap_or_default();
+            if self.file_store.delete(uuid).is_ok() {
+                deleted_files += 1;
+                reclaimed_bytes += size;
+            }
+        }
+
+        if deleted_files > 0 {
+            tracing::info!(
+                "Deleted {deleted_files} orphaned update files, reclaiming {reclaimed_bytes} bytes."
+            );
+        }
+
+        Ok(())
+    }
+
     /// Perform one iteration of the run loop.
     ///
     /// 1. See if we need to cleanup the task queue
@@ -1825,6 +1896,7 @@ mod tests {
                 auth_path: tempdir.path().join("auth"),
                 tasks_path: tempdir.path().join("db_path"),
                 update_file_path: tempdir.path().join("file_store"),
+                fsync_update_files: false,
                 indexes_path: tempdir.path().join("indexes"),
                 snapshots_path: tempdir.path().join("snapshots"),
                 dumps_path: tempdir.path().join("dumps"),
@@ -2111,6 +2183,28 @@ mod tests {
         snapshot!(snapshot_index_scheduler(&index_scheduler), name: "everything_is_successfully_registered");
     }
 
+    #[test]
+    fn delete_orphaned_update_files() {
+        let (index_scheduler, mut _handle) = IndexScheduler::test(true, vec![]);
+
+        let (_orphan, file) = index_scheduler.create_update_file_with_uuid(0).unwrap();
+        file.persist().unwrap();
+        let (referenced, file) = index_scheduler.create_update_file_with_uuid(1).unwrap();
+        file.persist().unwrap();
+        let kind = replace_document_import_task("catto", None, 1, 12);
+        index_scheduler.register(kind, None, false).unwrap();
+
+        index_scheduler.delete_orphaned_update_files().unwrap();
+
+        let files = index_scheduler
+            .file_store
+            .all_uuids()
+            .unwrap()
+            .collect::<file_store::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(files, vec![referenced]);
+    }
+
     #[test]
     fn insert_task_while_another_task_is_processing() {
         let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
@@ -5005,6 +5099,37 @@ mod tests {
         "###);
     }
 
+    #[test]
+    fn oldest_enqueued_at() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+        assert_eq!(index_scheduler.oldest_enqueued_at().unwrap(), None);
+
+        let kind = index_creation_task("catto", "mouse");
+        let first = index_scheduler.register(kind, None, false).unwrap();
+        let kind = index_creation_task("doggo", "sheep");
+        let second = index_scheduler.register(kind, None, false).unwrap();
+        let kind = index_creation_task("whalo", "fish");
+        let _task = index_scheduler.register(kind, None, false).unwrap();
+
+        assert_eq!(index_scheduler.oldest_enqueued_at().unwrap(), Some(first.enqueued_at));
+
+        // a processing task is still waiting to be finished
+        handle.advance_till([Start, BatchCreated]);
+        assert_eq!(index_scheduler.oldest_enqueued_at().unwrap(), Some(first.enqueued_at));
+
+        handle.advance_till([
+            InsideProcessBatch,
+            InsideProcessBatch,
+            ProcessBatchSucceeded,
+            AfterProcessing,
+        ]);
+        assert_eq!(index_scheduler.oldest_enqueued_at().unwrap(), Some(second.enqueued_at));
+
+        handle.advance_n_successful_batches(2);
+        assert_eq!(index_scheduler.oldest_enqueued_at().unwrap(), None);
+    }
+
     #[test]
     fn cancel_processing_dump() {
         let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
@@ -5027,6 +5152,81 @@ mod tests {
         snapshot!(snapshot_index_scheduler(&index_scheduler), name: "cancel_processed");
     }
 
+    #[test]
+    fn cancel_processing_snapshot() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+        // the snapshot starts by copying the version file, which the test scheduler doesn't write
+        std::fs::write(&index_scheduler.version_file_path, "1.11.0").unwrap();
+
+        let snapshot_cancellation = KindWithContent::TaskCancelation {
+            query: "cancel snapshot".to_owned(),
+            tasks: RoaringBitmap::from_iter([0]),
+        };
+        let _ = index_scheduler.register(KindWithContent::SnapshotCreation, None, false).unwrap();
+        handle.advance_till([Start, BatchCreated, InsideProcessBatch]);
+
+        let _ = index_scheduler.register(snapshot_cancellation, None, false).unwrap();
+
+        snapshot!(format!("{:?}", handle.advance()), @"AbortedIndexation");
+
+        handle.advance_one_successful_batch();
+        let rtxn = index_scheduler.env.read_txn().unwrap();
+        let task = index_scheduler.get_task(&rtxn, 0).unwrap().unwrap();
+        assert_eq!(task.status, Status::Canceled);
+        // no partial snapshot must be left behind
+        let snapshots = std::fs::read_dir(&index_scheduler.snapshots_path).unwrap();
+        assert_eq!(snapshots.count(), 0);
+    }
+
+    #[test]
+    fn snapshot_removes_leftover_temporary_snapshots() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+        // the snapshot copies the version file and the auth env, which the test scheduler doesn't create
+        std::fs::write(&index_scheduler.version_file_path, "1.11.0").unwrap();
+        std::fs::create_dir_all(&index_scheduler.auth_path).unwrap();
+
+        let db_name = index_scheduler.env.path().parent().unwrap().file_name().unwrap();
+        let db_name = db_name.to_str().unwrap();
+        std::fs::create_dir_all(&index_scheduler.snapshots_path).unwrap();
+        let leftover = index_scheduler.snapshots_path.join(format!(".{db_name}.snapshotAbC123"));
+        std::fs::write(&leftover, "half a snapshot").unwrap();
+        // a leftover that can't be removed must not make the snapshot fail
+        let undeletable = index_scheduler.snapshots_path.join(format!(".{db_name}.snapshotDir"));
+        std::fs::create_dir(&undeletable).unwrap();
+
+        index_scheduler.register(KindWithContent::SnapshotCreation, None, false).unwrap();
+        handle.advance_one_successful_batch();
+
+        let mut snapshots: Vec<_> = std::fs::read_dir(&index_scheduler.snapshots_path)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        snapshots.sort();
+        assert_eq!(
+            snapshots,
+            vec![format!(".{db_name}.snapshotDir"), format!("{db_name}.snapshot")]
+        );
+    }
+
+    #[test]
+    fn leftover_temporary_snapshots_are_removed_at_startup() {
+        let db_dir = TempDir::new().unwrap();
+        let snapshots_dir = TempDir::new().unwrap();
+        let leftover = snapshots_dir.path().join(".data.ms.snapshotAbC123");
+        std::fs::write(&leftover, "half a snapshot").unwrap();
+        let other_db_leftover = snapshots_dir.path().join(".other.snapshotAbC123");
+        std::fs::write(&other_db_leftover, "half a snapshot of another database").unwrap();
+
+        let (_index_scheduler, _handle) =
+            IndexScheduler::test_with_custom_config(vec![], |config| {
+                config.tasks_path = db_dir.path().join("data.ms").join("tasks");
+                config.snapshots_path = snapshots_dir.path().to_owned();
+            });
+
+        assert!(!leftover.exists());
+        assert!(other_db_leftover.exists());
+    }
+
     #[test]
     fn basic_set_taskid() {
         let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
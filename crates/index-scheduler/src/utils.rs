@@ -1,7 +1,9 @@
 //! Utility functions on the DBs. Mainly getter and setters.
 
 use std::collections::{BTreeSet, HashSet};
+use std::fs;
 use std::ops::Bound;
+use std::path::Path;
 
 use meilisearch_types::heed::types::DecodeIgnore;
 use meilisearch_types::heed::{Database, RoTxn, RwTxn};
@@ -326,6 +328,30 @@ pub(crate) fn check_index_swap_validity(task: &Task) -> Result<()> {
     Ok(())
 }
 
+/// Remove the temporary files starting with `prefix` that a crash left in `dir`.
+///
+/// This is housekeeping: a file that can't be removed is logged and skipped.
+pub(crate) fn remove_leftover_temp_files(dir: &Path, prefix: &str) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return,
+        Err(e) => {
+            tracing::warn!(dir = %dir.display(), "Could not look for leftover temporary files: {e}");
+            return;
+        }
+    };
+    for entry in entries.flatten() {
+        if !entry.file_name().to_str().is_some_and(|name| name.starts_with(prefix)) {
+            continue;
+        }
+        let path = entry.path();
+        tracing::warn!(path = %path.display(), "Removing a leftover temporary file");
+        if let Err(e) = fs::remove_file(&path) {
+            tracing::warn!(path = %path.display(), "Could not remove a leftover temporary file: {e}");
+        }
+    }
+}
+
 /// Clamp the provided value to be a multiple of system page size.
 pub fn clamp_to_page_size(size: usize) -> usize {
     size / page_size::get() * page_size::get()
@@ -14,6 +14,9 @@ use crate::error::{Code, ErrorCode};
 
 type Result<T> = std::result::Result<T, DocumentFormatError>;
 
+/// The byte order mark that some editors, mostly on Windows, write at the start of UTF-8 files.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
 #[derive(Debug)]
 pub enum PayloadType {
     Ndjson,
@@ -107,7 +110,7 @@ impl ErrorCode for DocumentFormatError {
 pub fn read_csv(file: &File, writer: impl Write, delimiter: u8) -> Result<u64> {
     let mut builder = DocumentsBatchBuilder::new(BufWriter::new(writer));
     let mmap = unsafe { MmapOptions::new().map(file)? };
-    let csv = csv::ReaderBuilder::new().delimiter(delimiter).from_reader(mmap.as_ref());
+    let csv = csv::ReaderBuilder::new().delimiter(delimiter).from_reader(skip_utf8_bom(&mmap));
     builder.append_csv(csv).map_err(|e| (PayloadType::Csv { delimiter }, e))?;
 
     let count = builder.documents_count();
@@ -120,7 +123,8 @@ pub fn read_csv(file: &File, writer: impl Write, delimiter: u8) -> Result<u64> {
 pub fn read_json(file: &File, writer: impl Write) -> Result<u64> {
     let mut builder = DocumentsBatchBuilder::new(BufWriter::new(writer));
     let mmap = unsafe { MmapOptions::new().map(file)? };
-    let mut deserializer = serde_json::Deserializer::from_slice(&mmap);
+    let payload = skip_utf8_bom(&mmap);
+    let mut deserializer = serde_json::Deserializer::from_slice(payload);
 
     match array_each(&mut deserializer, |obj| builder.append_json_object(&obj)) {
         // The json data has been deserialized and does not need to be processed again.
@@ -137,7 +141,7 @@ pub fn read_json(file: &File, writer: impl Write) -> Result<u64> {
                 ));
             }
 
-            let content: Object = serde_json::from_slice(&mmap)
+            let content: Object = serde_json::from_slice(payload)
                 .map_err(Error::Json)
                 .map_err(|e| (PayloadType::Json, e))?;
             builder.append_json_object(&content).map_err(DocumentFormatError::Io)?;
@@ -155,7 +159,7 @@ pub fn read_ndjson(file: &File, writer: impl Write) -> Result<u64> {
     let mut builder = DocumentsBatchBuilder::new(BufWriter::new(writer));
     let mmap = unsafe { MmapOptions::new().map(file)? };
 
-    for result in serde_json::Deserializer::from_slice(&mmap).into_iter() {
+    for result in serde_json::Deserializer::from_slice(skip_utf8_bom(&mmap)).into_iter() {
         let object = result.map_err(Error::Json).map_err(|e| (PayloadType::Ndjson, e))?;
         builder.append_json_object(&object).map_err(Into::into).map_err(DocumentFormatError::Io)?;
     }
@@ -166,6 +170,11 @@ pub fn read_ndjson(file: &File, writer: impl Write) -> Result<u64> {
     Ok(count as u64)
 }
 
+/// Returns the payload without its leading UTF-8 byte order mark, if any.
+fn skip_utf8_bom(payload: &[u8]) -> &[u8] {
+    payload.strip_prefix(UTF8_BOM).unwrap_or(payload)
+}
+
 /// The actual handling of the deserialization process in serde
 /// avoids storing the deserialized object in memory.
 ///
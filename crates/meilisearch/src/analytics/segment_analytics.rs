@@ -194,6 +194,8 @@ struct Infos {
     experimental_enable_logs_route: bool,
     experimental_reduce_indexing_memory_usage: bool,
     experimental_max_number_of_batched_tasks: usize,
+    experimental_update_files_dir: bool,
+    experimental_fsync_update_files: bool,
     gpu_enabled: bool,
     db_path: bool,
     import_dump: bool,
@@ -239,6 +241,8 @@ impl Infos {
             experimental_enable_logs_route,
             experimental_reduce_indexing_memory_usage,
             experimental_max_number_of_batched_tasks,
+            experimental_update_files_dir,
+            experimental_fsync_update_files,
             http_addr,
             master_key: _,
             env,
@@ -314,6 +318,8 @@ impl Infos {
             http_addr: http_addr != default_http_addr(),
             http_payload_size_limit,
             experimental_max_number_of_batched_tasks,
+            experimental_update_files_dir: experimental_update_files_dir.is_some(),
+            experimental_fsync_update_files,
             task_queue_webhook: task_webhook_url.is_some(),
             task_webhook_authorization_header: task_webhook_authorization_header.is_some(),
             log_level: log_level.to_string(),
@@ -97,7 +97,7 @@ impl ErrorCode for MeilisearchHttpError {
             MeilisearchHttpError::IndexScheduler(e) => e.error_code(),
             MeilisearchHttpError::Milli(e) => e.error_code(),
             MeilisearchHttpError::Payload(e) => e.error_code(),
-            MeilisearchHttpError::FileStore(_) => Code::Internal,
+            MeilisearchHttpError::FileStore(e) => e.error_code(),
             MeilisearchHttpError::DocumentFormat(e) => e.error_code(),
             MeilisearchHttpError::Join(_) => Code::Internal,
             MeilisearchHttpError::MissingSearchHybrid => Code::MissingSearchHybrid,
@@ -189,7 +189,11 @@ impl ErrorCode for PayloadError {
             },
             PayloadError::MissingPayload => Code::MissingPayload,
             PayloadError::MalformedPayload(_) => Code::MalformedPayload,
-            PayloadError::ReceivePayload(_) => Code::Internal,
+            PayloadError::ReceivePayload(e) => match e.downcast_ref::<std::io::Error>() {
+                // e.g. the disk is full while we write the payload
+                Some(e) => e.error_code(),
+                None => Code::Internal,
+            },
         }
     }
 }
@@ -225,3 +229,18 @@ impl From<PayloadError> for aweb::Error {
         aweb::Error::from(ResponseError::from(other))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn receive_payload_keeps_the_io_error_code() {
+        let no_space = std::io::Error::from_raw_os_error(28);
+        let error = PayloadError::ReceivePayload(Box::new(no_space));
+        assert_eq!(error.error_code(), Code::NoSpaceLeftOnDevice);
+
+        let error = PayloadError::ReceivePayload("not an io error".into());
+        assert_eq!(error.error_code(), Code::Internal);
+    }
+}
@@ -209,7 +209,11 @@ pub fn setup_meilisearch(opt: &Opt) -> anyhow::Result<(Arc<IndexScheduler>, Arc<
         let snapshot_path_exists = snapshot_path.exists();
         // the db is empty and the snapshot exists, import it
         if empty_db && snapshot_path_exists {
-            match compression::from_tar_gz(snapshot_path, &opt.db_path) {
+            // a snapshot can only be restored by the version of Meilisearch that created it
+            match compression::from_tar_gz(snapshot_path, &opt.db_path)
+                .and_then(|()| check_version_file(&opt.db_path))
+                .and_then(|()| move_update_files(&opt.db_path, &opt.update_files_dir()))
+            {
                 Ok(()) => open_or_create_database_unchecked(opt, OnFailure::RemoveDb)?,
                 Err(e) => {
                     std::fs::remove_dir_all(&opt.db_path)?;
@@ -282,6 +286,34 @@ pub fn setup_meilisearch(opt: &Opt) -> anyhow::Result<(Arc<IndexScheduler>, Arc<
     Ok((index_scheduler, auth_controller))
 }
 
+/// Move the update files stored under `<db_path>/update_files` to `dst`, the directory the
+/// instance is configured to use.
+///
+/// Snapshots always store the update files there, and so does an instance restarted with a
+/// different update files directory. Without the move, their enqueued tasks would lose their payloads.
+fn move_update_files(db_path: &Path, dst: &Path) -> anyhow::Result<()> {
+    let src = db_path.join("update_files");
+    if !src.exists() {
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(dst)?;
+    // copying a file onto itself would truncate it, the paths must be compared once resolved.
+    if src.canonicalize()? == dst.canonicalize()? {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(&src)? {
+        let entry = entry?;
+        let dst = dst.join(entry.file_name());
+        // the update files directory may be on another volume, a rename wouldn't work there.
+        std::fs::copy(entry.path(), dst)?;
+        std::fs::remove_file(entry.path())?;
+    }
+    std::fs::remove_dir(src)?;
+
+    Ok(())
+}
+
 /// Try to start the IndexScheduler and AuthController without checking the VERSION file or anything.
 fn open_or_create_database_unchecked(
     opt: &Opt,
@@ -296,7 +328,8 @@ fn open_or_create_database_unchecked(
             version_file_path: opt.db_path.join(VERSION_FILE_NAME),
             auth_path: opt.db_path.join("auth"),
             tasks_path: opt.db_path.join("tasks"),
-            update_file_path: opt.db_path.join("update_files"),
+            update_file_path: opt.update_files_dir(),
+            fsync_update_files: opt.experimental_fsync_update_files,
             indexes_path: opt.db_path.join("indexes"),
             snapshots_path: opt.snapshot_dir.clone(),
             dumps_path: opt.dump_dir.clone(),
@@ -338,6 +371,7 @@ fn open_or_create_database(
 ) -> anyhow::Result<(IndexScheduler, AuthController)> {
     if !empty_db {
         check_version_file(&opt.db_path)?;
+        move_update_files(&opt.db_path, &opt.update_files_dir())?;
     }
 
     open_or_create_database_unchecked(opt, OnFailure::KeepDb)
@@ -543,3 +577,44 @@ pub fn dashboard(config: &mut web::ServiceConfig, enable_frontend: bool) {
 pub fn dashboard(config: &mut web::ServiceConfig, _enable_frontend: bool) {
     config.service(web::resource("/").route(web::get().to(routes::running)));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn move_update_files_to_a_custom_dir() {
+        let temp = tempfile::tempdir().unwrap();
+        let db_path = temp.path().join("data.ms");
+        std::fs::create_dir_all(db_path.join("update_files")).unwrap();
+        std::fs::write(db_path.join("update_files").join("payload"), "kefir").unwrap();
+
+        let dst = temp.path().join("custom_update_files");
+        move_update_files(&db_path, &dst).unwrap();
+
+        assert!(!db_path.join("update_files").exists());
+        assert_eq!(std::fs::read_to_string(dst.join("payload")).unwrap(), "kefir");
+    }
+
+    #[test]
+    fn move_update_files_to_the_same_dir_spelled_differently() {
+        let temp = tempfile::tempdir().unwrap();
+        let db_path = temp.path().join("data.ms");
+        std::fs::create_dir_all(db_path.join("update_files")).unwrap();
+        std::fs::write(db_path.join("update_files").join("payload"), "kefir").unwrap();
+
+        let dst = db_path.join(".").join("update_files");
+        move_update_files(&db_path, &dst).unwrap();
+
+        let content = std::fs::read_to_string(db_path.join("update_files").join("payload"));
+        assert_eq!(content.unwrap(), "kefir");
+    }
+
+    #[test]
+    fn move_update_files_without_update_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let dst = temp.path().join("custom_update_files");
+        move_update_files(&temp.path().join("data.ms"), &dst).unwrap();
+        assert!(!dst.exists());
+    }
+}
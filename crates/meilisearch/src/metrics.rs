@@ -1,7 +1,8 @@
 use lazy_static::lazy_static;
 use prometheus::{
-    opts, register_histogram_vec, register_int_counter_vec, register_int_gauge,
-    register_int_gauge_vec, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec,
+    opts, register_gauge, register_histogram_vec, register_int_counter, register_int_counter_vec,
+    register_int_gauge, register_int_gauge_vec, Gauge, HistogramVec, IntCounter, IntCounterVec,
+    IntGauge, IntGaugeVec,
 };
 
 lazy_static! {
@@ -15,6 +16,18 @@ lazy_static! {
         "Meilisearch number of degraded search requests"
     ))
     .expect("Can't create a metric");
+    pub static ref MEILISEARCH_RECEIVED_DOCUMENTS_PAYLOAD_BYTES: IntCounter =
+        register_int_counter!(opts!(
+            "meilisearch_received_documents_payload_bytes_total",
+            "Meilisearch number of bytes received in documents payloads"
+        ))
+        .expect("Can't create a metric");
+    pub static ref MEILISEARCH_MALFORMED_DOCUMENTS_PAYLOADS: IntCounter =
+        register_int_counter!(opts!(
+            "meilisearch_malformed_documents_payloads_total",
+            "Meilisearch number of rejected malformed documents payloads"
+        ))
+        .expect("Can't create a metric");
     pub static ref MEILISEARCH_DB_SIZE_BYTES: IntGauge =
         register_int_gauge!(opts!("meilisearch_db_size_bytes", "Meilisearch DB Size In Bytes"))
             .expect("Can't create a metric");
@@ -23,6 +36,11 @@ lazy_static! {
         "Meilisearch Used DB Size In Bytes"
     ))
     .expect("Can't create a metric");
+    pub static ref MEILISEARCH_UPDATE_FILES_SIZE_BYTES: IntGauge = register_int_gauge!(opts!(
+        "meilisearch_update_files_size_bytes",
+        "Meilisearch Update Files Size In Bytes"
+    ))
+    .expect("Can't create a metric");
     pub static ref MEILISEARCH_INDEX_COUNT: IntGauge =
         register_int_gauge!(opts!("meilisearch_index_count", "Meilisearch Index Count"))
             .expect("Can't create a metric");
@@ -43,6 +61,11 @@ lazy_static! {
         &["kind", "value"]
     )
     .expect("Can't create a metric");
+    pub static ref MEILISEARCH_OLDEST_ENQUEUED_TASK_AGE_SECONDS: Gauge = register_gauge!(opts!(
+        "meilisearch_oldest_enqueued_task_age_seconds",
+        "Meilisearch Age In Seconds Of The Oldest Enqueued Or Processing Task"
+    ))
+    .expect("Can't create a metric");
     pub static ref MEILISEARCH_LAST_UPDATE: IntGauge =
         register_int_gauge!(opts!("meilisearch_last_update", "Meilisearch Last Update"))
             .expect("Can't create a metric");
@@ -60,6 +60,8 @@ const MEILI_EXPERIMENTAL_REDUCE_INDEXING_MEMORY_USAGE: &str =
     "MEILI_EXPERIMENTAL_REDUCE_INDEXING_MEMORY_USAGE";
 const MEILI_EXPERIMENTAL_MAX_NUMBER_OF_BATCHED_TASKS: &str =
     "MEILI_EXPERIMENTAL_MAX_NUMBER_OF_BATCHED_TASKS";
+const MEILI_EXPERIMENTAL_UPDATE_FILES_DIR: &str = "MEILI_EXPERIMENTAL_UPDATE_FILES_DIR";
+const MEILI_EXPERIMENTAL_FSYNC_UPDATE_FILES: &str = "MEILI_EXPERIMENTAL_FSYNC_UPDATE_FILES";
 
 const DEFAULT_CONFIG_FILE_PATH: &str = "./config.toml";
 const DEFAULT_DB_PATH: &str = "./data.ms";
@@ -411,6 +413,25 @@ pub struct Opt {
     #[serde(default = "default_limit_batched_tasks")]
     pub experimental_max_number_of_batched_tasks: usize,
 
+    /// Experimentally stores the payloads of the enqueued tasks in a directory other than `<db_path>/update_files`.
+    ///
+    /// Lets you put the update files on a different volume than the task queue and the indexes.
+    /// Snapshots and dumps still contain these files. At startup, the files found in `<db_path>/update_files`
+    /// are moved to this directory, but removing the option doesn't move them back.
+    ///
+    /// The directory must be dedicated to a single instance: at startup, Meilisearch deletes the files
+    /// in it that none of its tasks reference, which would include the payloads of another instance.
+    #[clap(long, env = MEILI_EXPERIMENTAL_UPDATE_FILES_DIR)]
+    pub experimental_update_files_dir: Option<PathBuf>,
+
+    /// Experimentally syncs the payloads of the documents additions to disk before acknowledging them.
+    ///
+    /// Without it, an enqueued task can lose its payload on a power loss. With it, every documents addition
+    /// waits for the disk, which noticeably slows down small and frequent additions.
+    #[clap(long, env = MEILI_EXPERIMENTAL_FSYNC_UPDATE_FILES)]
+    #[serde(default)]
+    pub experimental_fsync_update_files: bool,
+
     #[serde(flatten)]
     #[clap(flatten)]
     pub indexer_options: IndexerOpts,
@@ -427,6 +448,14 @@ impl Opt {
         !self.no_analytics
     }
 
+    /// The directory in which the payloads of the enqueued tasks are stored.
+    pub fn update_files_dir(&self) -> PathBuf {
+        match &self.experimental_update_files_dir {
+            Some(dir) => dir.clone(),
+            None => self.db_path.join("update_files"),
+        }
+    }
+
     /// Build a new Opt from config file, env vars and cli args.
     pub fn try_build() -> anyhow::Result<(Self, Option<PathBuf>)> {
         // Parse the args to get the config_file_path.
@@ -483,6 +512,8 @@ impl Opt {
             max_task_db_size: _,
             http_payload_size_limit,
             experimental_max_number_of_batched_tasks,
+            experimental_update_files_dir,
+            experimental_fsync_update_files,
             ssl_cert_path,
             ssl_key_path,
             ssl_auth_path,
@@ -538,6 +569,16 @@ impl Opt {
             MEILI_EXPERIMENTAL_MAX_NUMBER_OF_BATCHED_TASKS,
             experimental_max_number_of_batched_tasks.to_string(),
         );
+        if let Some(experimental_update_files_dir) = experimental_update_files_dir {
+            export_to_env_if_not_present(
+                MEILI_EXPERIMENTAL_UPDATE_FILES_DIR,
+                experimental_update_files_dir,
+            );
+        }
+        export_to_env_if_not_present(
+            MEILI_EXPERIMENTAL_FSYNC_UPDATE_FILES,
+            experimental_fsync_update_files.to_string(),
+        );
         if let Some(ssl_cert_path) = ssl_cert_path {
             export_to_env_if_not_present(MEILI_SSL_CERT_PATH, ssl_cert_path);
         }
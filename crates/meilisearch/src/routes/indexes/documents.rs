@@ -12,7 +12,9 @@ use futures::StreamExt;
 use index_scheduler::{IndexScheduler, RoFeatures, TaskId};
 use meilisearch_types::deserr::query_params::Param;
 use meilisearch_types::deserr::{DeserrJsonError, DeserrQueryParamError};
-use meilisearch_types::document_formats::{read_csv, read_json, read_ndjson, PayloadType};
+use meilisearch_types::document_formats::{
+    read_csv, read_json, read_ndjson, DocumentFormatError, PayloadType,
+};
 use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::{Code, ResponseError};
 use meilisearch_types::heed::RoTxn;
@@ -39,6 +41,9 @@ use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
 use crate::extractors::payload::Payload;
 use crate::extractors::sequential_extractor::SeqHandler;
+use crate::metrics::{
+    MEILISEARCH_MALFORMED_DOCUMENTS_PAYLOADS, MEILISEARCH_RECEIVED_DOCUMENTS_PAYLOAD_BYTES,
+};
 use crate::routes::{
     get_task_id, is_dry_run, PaginationView, SummarizedTaskView, PAGINATION_DEFAULT_LIMIT,
 };
@@ -627,7 +632,10 @@ async fn document_addition(
         }
 
         match buffer.write_all(&byte).await {
-            Ok(()) => buffer_write_size += 1,
+            Ok(()) => {
+                buffer_write_size += 1;
+                MEILISEARCH_RECEIVED_DOCUMENTS_PAYLOAD_BYTES.inc_by(byte.len() as u64);
+            }
             Err(e) => return Err(MeilisearchHttpError::Payload(ReceivePayload(Box::new(e)))),
         }
     }
@@ -660,7 +668,15 @@ async fn document_addition(
     let documents_count = match documents_count {
         Ok(Ok(documents_count)) => documents_count,
         // in this case the file has not possibly be persisted.
-        Ok(Err(e)) => return Err(e),
+        Ok(Err(e)) => {
+            if matches!(
+                e,
+                MeilisearchHttpError::DocumentFormat(DocumentFormatError::MalformedPayload(..))
+            ) {
+                MEILISEARCH_MALFORMED_DOCUMENTS_PAYLOADS.inc();
+            }
+            return Err(e);
+        }
         Err(e) => {
             // Here the file MAY have been persisted or not.
             // We don't know thus we ignore the file not found error.
@@ -6,6 +6,7 @@ use meilisearch_auth::AuthController;
 use meilisearch_types::error::ResponseError;
 use meilisearch_types::keys::actions;
 use prometheus::{Encoder, TextEncoder};
+use time::OffsetDateTime;
 
 use crate::extractors::authentication::policies::ActionPolicy;
 use crate::extractors::authentication::{AuthenticationError, GuardedData};
@@ -35,6 +36,8 @@ pub async fn get_metrics(
 
     crate::metrics::MEILISEARCH_DB_SIZE_BYTES.set(response.database_size as i64);
     crate::metrics::MEILISEARCH_USED_DB_SIZE_BYTES.set(response.used_database_size as i64);
+    crate::metrics::MEILISEARCH_UPDATE_FILES_SIZE_BYTES
+        .set(index_scheduler.compute_update_file_size()? as i64);
     crate::metrics::MEILISEARCH_INDEX_COUNT.set(response.indexes.len() as i64);
 
     crate::metrics::MEILISEARCH_SEARCH_QUEUE_SIZE.set(search_queue.capacity() as i64);
@@ -56,6 +59,13 @@ pub async fn get_metrics(
         }
     }
 
+    let oldest_enqueued_task_age_seconds = index_scheduler
+        .oldest_enqueued_at()?
+        .map(|enqueued_at| (OffsetDateTime::now_utc() - enqueued_at).as_seconds_f64())
+        .unwrap_or(0.0);
+    crate::metrics::MEILISEARCH_OLDEST_ENQUEUED_TASK_AGE_SECONDS
+        .set(oldest_enqueued_task_age_seconds);
+
     if let Some(last_update) = response.last_update {
         crate::metrics::MEILISEARCH_LAST_UPDATE.set(last_update.unix_timestamp());
     }
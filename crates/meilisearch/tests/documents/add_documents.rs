@@ -332,6 +332,61 @@ async fn add_csv_document() {
     "###);
 }
 
+#[actix_rt::test]
+async fn add_documents_with_a_utf8_bom() {
+    let server = Server::new().await;
+    let index = server.index("pets");
+
+    let (response, code) = index
+        .raw_add_documents(
+            "\u{feff}[{ \"id\": 0, \"name\": \"jean\" }]",
+            vec![("Content-Type", "application/json")],
+            "",
+        )
+        .await;
+    snapshot!(code, @"202 Accepted");
+    index.wait_task(response.uid()).await.succeeded();
+
+    let (response, code) = index
+        .raw_add_documents(
+            "\u{feff}{ \"id\": 1, \"name\": \"jorts\" }",
+            vec![("Content-Type", "application/x-ndjson")],
+            "",
+        )
+        .await;
+    snapshot!(code, @"202 Accepted");
+    index.wait_task(response.uid()).await.succeeded();
+
+    let (response, code) =
+        index.raw_update_documents("\u{feff}id,name\n2,kefir", Some("text/csv"), "").await;
+    snapshot!(code, @"202 Accepted");
+    index.wait_task(response.uid()).await.succeeded();
+
+    let (documents, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(documents), @r###"
+    {
+      "results": [
+        {
+          "id": 0,
+          "name": "jean"
+        },
+        {
+          "id": 1,
+          "name": "jorts"
+        },
+        {
+          "id": "2",
+          "name": "kefir"
+        }
+      ],
+      "offset": 0,
+      "limit": 20,
+      "total": 3
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn add_csv_document_with_types() {
     let server = Server::new().await;
@@ -761,6 +816,35 @@ async fn error_add_malformed_json_documents() {
     "###);
 }
 
+/// The position reported in the error must point at the exact spot in the user's payload.
+#[actix_rt::test]
+async fn error_add_json_documents_with_trailing_comma() {
+    let document = "[\n  {\"id\": 1},\n]";
+
+    let server = Server::new().await;
+    let app = server.init_web_app().await;
+
+    let req = test::TestRequest::post()
+        .uri("/indexes/dog/documents")
+        .set_payload(document.to_string())
+        .insert_header(("content-type", "application/json"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    let status_code = res.status();
+    let body = test::read_body(res).await;
+    let response: Value = serde_json::from_slice(&body).unwrap_or_default();
+    snapshot!(status_code, @"400 Bad Request");
+    snapshot!(json_string!(response),
+        @r###"
+    {
+      "message": "The `json` payload provided is malformed. `Couldn't serialize document value: trailing comma at line 3 column 1`.",
+      "code": "malformed_payload",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#malformed_payload"
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn error_add_malformed_ndjson_documents() {
     let document = "{\"id\": 1}\n{id: 2}";
@@ -2521,3 +2605,32 @@ async fn dry_register_file() {
     "###);
     snapshot!(code, @"404 Not Found");
 }
+
+#[actix_rt::test]
+async fn add_documents_with_a_custom_update_files_dir() {
+    let temp = tempfile::tempdir().unwrap();
+    let update_files_dir = temp.path().join("custom_update_files");
+
+    let options = Opt {
+        experimental_update_files_dir: Some(update_files_dir.clone()),
+        ..default_settings(temp.path())
+    };
+    let server = Server::new_with_options(options).await.unwrap();
+    let index = server.index("tamo");
+
+    let (response, code) = index.add_documents(json!([{ "id": 1, "doggo": "kefir" }]), None).await;
+    snapshot!(code, @"202 Accepted");
+    index.wait_task(response.uid()).await.succeeded();
+
+    assert!(update_files_dir.is_dir());
+    assert!(!temp.path().join("db").join("update_files").exists());
+
+    let (response, code) = index.get_document(1, None).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response, @r###"
+    {
+      "id": 1,
+      "doggo": "kefir"
+    }
+    "###);
+}
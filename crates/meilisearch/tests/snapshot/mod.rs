@@ -202,3 +202,23 @@ async fn perform_on_demand_snapshot() {
         server.index("doggo").settings(),
     );
 }
+
+#[actix_rt::test]
+async fn import_a_snapshot_from_another_version() {
+    let snapshot_dir = tempfile::tempdir().unwrap();
+    let content = tempfile::tempdir().unwrap();
+    std::fs::write(content.path().join("VERSION"), "0.1.0").unwrap();
+    let snapshot_path = snapshot_dir.path().join("db.snapshot");
+    meilisearch_types::compression::to_tar_gz(content.path(), &snapshot_path).unwrap();
+
+    let temp = tempfile::tempdir().unwrap();
+    let options = Opt { import_snapshot: Some(snapshot_path), ..default_settings(temp.path()) };
+    let error = Server::new_with_options(options).await.map(|_| ()).unwrap_err();
+
+    assert!(
+        error.to_string().starts_with("Your database version (0.1.0) is incompatible"),
+        "{error}"
+    );
+    // the imported content is not kept around
+    assert!(!temp.path().join("db").exists());
+}
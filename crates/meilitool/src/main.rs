@@ -29,6 +29,12 @@ struct Cli {
     #[arg(long, default_value = "data.ms/")]
     db_path: PathBuf,
 
+    /// The directory containing the update files, if Meilisearch runs with `--experimental-update-files-dir`.
+    ///
+    /// Defaults to the `update_files` directory of the database.
+    #[arg(long)]
+    update_files_dir: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Command,
 }
@@ -81,14 +87,15 @@ enum Command {
 }
 
 fn main() -> anyhow::Result<()> {
-    let Cli { db_path, command } = Cli::parse();
+    let Cli { db_path, update_files_dir, command } = Cli::parse();
 
     let detected_version = get_version(&db_path).context("While checking the version file")?;
+    let update_files_dir = update_files_dir.unwrap_or_else(|| db_path.join("update_files"));
 
     match command {
-        Command::ClearTaskQueue => clear_task_queue(db_path),
+        Command::ClearTaskQueue => clear_task_queue(db_path, update_files_dir),
         Command::ExportADump { dump_dir, skip_enqueued_tasks } => {
-            export_a_dump(db_path, dump_dir, skip_enqueued_tasks)
+            export_a_dump(db_path, update_files_dir, dump_dir, skip_enqueued_tasks)
         }
         Command::OfflineUpgrade { target_version } => {
             let target_version = parse_version(&target_version).context("While parsing `--target-version`. Make sure `--target-version` is in the format MAJOR.MINOR.PATCH")?;
@@ -97,8 +104,8 @@ fn main() -> anyhow::Result<()> {
     }
 }
 
-/// Clears the task queue located at `db_path`.
-fn clear_task_queue(db_path: PathBuf) -> anyhow::Result<()> {
+/// Clears the task queue located at `db_path` and the update files located in `update_files`.
+fn clear_task_queue(db_path: PathBuf, update_files: PathBuf) -> anyhow::Result<()> {
     let path = db_path.join("tasks");
     let env = unsafe { EnvOpenOptions::new().max_dbs(100).open(&path) }
         .with_context(|| format!("While trying to open {:?}", path.display()))?;
@@ -131,7 +138,6 @@ fn clear_task_queue(db_path: PathBuf) -> anyhow::Result<()> {
     eprintln!("Deleting the content files from disk...");
 
     let mut count = 0usize;
-    let update_files = db_path.join("update_files");
     let entries = read_dir(&update_files).with_context(|| {
         format!("While trying to read the content of {:?}", update_files.display())
     })?;
@@ -185,6 +191,7 @@ fn try_clearing_poly_database(
 /// Exports a dump into the dump directory.
 fn export_a_dump(
     db_path: PathBuf,
+    update_files_dir: PathBuf,
     dump_dir: PathBuf,
     skip_enqueued_tasks: bool,
 ) -> Result<(), anyhow::Error> {
@@ -207,8 +214,7 @@ fn export_a_dump(
     };
 
     let dump = DumpWriter::new(instance_uid).context("While creating a new dump")?;
-    let file_store =
-        FileStore::new(db_path.join("update_files")).context("While opening the FileStore")?;
+    let file_store = FileStore::new(update_files_dir).context("While opening the FileStore")?;
 
     let index_scheduler_path = db_path.join("tasks");
     let env = unsafe { EnvOpenOptions::new().max_dbs(100).open(&index_scheduler_path) }